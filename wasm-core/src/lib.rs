@@ -1,20 +1,84 @@
+mod relationships;
+
 use std::io::{Cursor, Read};
 
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use quick_xml::events::Event;
+use quick_xml::{Reader, Writer};
 use serde::Serialize;
 use wasm_bindgen::prelude::*;
 use zip::read::ZipArchive;
 
+use relationships::{ContentTypes, Relationship};
+
 #[derive(Serialize)]
 pub struct ArchiveEntry {
     path: String,
     is_dir: bool,
     size: u64,
     content: Option<String>,
+    /// Re-indented form of `content`, when `inspect_ooxml` was asked to pretty-print and the
+    /// part parses.
+    formatted: Option<String>,
+    /// Content type resolved from `[Content_Types].xml`, when present.
+    content_type: Option<String>,
+    /// `data:` URI for binary parts under the `max_inline_bytes` cap passed to `inspect_ooxml`.
+    data_uri: Option<String>,
+    /// Set when a textual entry fails to parse as well-formed XML.
+    parse_error: Option<XmlError>,
+}
+
+/// Where and why a textual part failed to parse as well-formed XML.
+#[derive(Serialize)]
+pub struct XmlError {
+    message: String,
+    line: usize,
+    column: usize,
+    byte_offset: usize,
 }
 
 #[derive(Serialize)]
 pub struct ArchiveSummary {
     entries: Vec<ArchiveEntry>,
+    relationships: Vec<Relationship>,
+}
+
+/// Cheap per-entry metadata returned by `list_entries`; only `[Content_Types].xml` is
+/// decompressed to fill it.
+#[derive(Serialize)]
+pub struct EntryMeta {
+    path: String,
+    is_dir: bool,
+    size: u64,
+    content_type: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct EntryList {
+    entries: Vec<EntryMeta>,
+}
+
+/// A single decompressed part, returned on demand by `read_entry`. `content` holds the text of
+/// textual parts and is `None` for binary parts.
+#[derive(Serialize)]
+pub struct EntryContent {
+    path: String,
+    content: Option<String>,
+}
+
+/// Plain text extracted from a single docx/pptx part.
+#[derive(Serialize)]
+pub struct PartText {
+    path: String,
+    /// Zero-based position of the slide in deck order, for pptx slides; `None` for docx.
+    slide: Option<usize>,
+    text: String,
+}
+
+#[derive(Serialize)]
+pub struct TextSummary {
+    parts: Vec<PartText>,
 }
 
 /// Initialise shared panic hook so Rust panics show up in the browser console.
@@ -23,20 +87,229 @@ pub fn init() {
     set_panic_hook();
 }
 
-/// Inspect an OOXML archive (docx, pptx) and return its entry metadata + XML contents.
+/// Inspect an OOXML archive (docx, pptx) and return its entry metadata + XML contents. When
+/// `pretty` is true, textual entries also get a re-indented `formatted` rendering. Binary parts
+/// no larger than `max_inline_bytes` are attached as base64 `data:` URIs; pass `0` to skip
+/// inlining binary parts entirely.
 #[wasm_bindgen]
-pub fn inspect_ooxml(bytes: &[u8]) -> Result<JsValue, JsValue> {
-    match inspect_archive(bytes) {
+pub fn inspect_ooxml(bytes: &[u8], pretty: bool, max_inline_bytes: u64) -> Result<JsValue, JsValue> {
+    match inspect_archive(bytes, pretty, max_inline_bytes) {
         Ok(summary) => serde_wasm_bindgen::to_value(&summary).map_err(|err| err.into()),
         Err(err) => Err(JsValue::from_str(&err)),
     }
 }
 
-fn inspect_archive(bytes: &[u8]) -> Result<ArchiveSummary, String> {
+/// List archive entries without decompressing any part payload other than `[Content_Types].xml`
+/// — cheap enough to run on every file open, even for multi-hundred-megabyte pptx decks full of
+/// embedded media.
+#[wasm_bindgen]
+pub fn list_entries(bytes: &[u8]) -> Result<JsValue, JsValue> {
+    match list_archive_entries(bytes) {
+        Ok(list) => serde_wasm_bindgen::to_value(&list).map_err(|err| err.into()),
+        Err(err) => Err(JsValue::from_str(&err)),
+    }
+}
+
+fn list_archive_entries(bytes: &[u8]) -> Result<EntryList, String> {
     let cursor = Cursor::new(bytes.to_vec());
     let mut archive = ZipArchive::new(cursor).map_err(|err| err.to_string())?;
 
+    let content_types = read_content_types(&mut archive)?;
+
     let mut entries = Vec::with_capacity(archive.len());
+    for index in 0..archive.len() {
+        let file = archive.by_index(index).map_err(|err| err.to_string())?;
+        let is_dir = file.is_dir();
+        let name = file.name().to_string();
+        let size = file.size();
+
+        let path = if is_dir {
+            name.trim_end_matches('/').to_string()
+        } else {
+            name
+        };
+        let content_type = if is_dir {
+            None
+        } else {
+            content_types.as_ref().and_then(|ct| ct.resolve(&path))
+        };
+
+        entries.push(EntryMeta {
+            path,
+            is_dir,
+            size,
+            content_type,
+        });
+    }
+
+    Ok(EntryList { entries })
+}
+
+/// Decompress exactly one part by path, for on-demand access after `list_entries`. Textual parts
+/// are returned as text; for binary parts only the path is returned and `content` is `None`.
+#[wasm_bindgen]
+pub fn read_entry(bytes: &[u8], path: &str) -> Result<JsValue, JsValue> {
+    match read_archive_entry(bytes, path) {
+        Ok(entry) => serde_wasm_bindgen::to_value(&entry).map_err(|err| err.into()),
+        Err(err) => Err(JsValue::from_str(&err)),
+    }
+}
+
+fn read_archive_entry(bytes: &[u8], path: &str) -> Result<EntryContent, String> {
+    let cursor = Cursor::new(bytes.to_vec());
+    let mut archive = ZipArchive::new(cursor).map_err(|err| err.to_string())?;
+
+    let mut file = archive.by_name(path).map_err(|err| err.to_string())?;
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer).map_err(|err| err.to_string())?;
+
+    let content = if is_textual_entry(path) {
+        Some(String::from_utf8_lossy(&buffer).into_owned())
+    } else {
+        None
+    };
+
+    Ok(EntryContent {
+        path: path.to_string(),
+        content,
+    })
+}
+
+/// Read `[Content_Types].xml` only, if present, without touching any other part.
+fn read_content_types(
+    archive: &mut ZipArchive<Cursor<Vec<u8>>>,
+) -> Result<Option<ContentTypes>, String> {
+    match archive.by_name("[Content_Types].xml") {
+        Ok(mut file) => {
+            let mut xml = String::new();
+            file.read_to_string(&mut xml).map_err(|err| err.to_string())?;
+            Ok(Some(ContentTypes::parse(&xml)?))
+        }
+        Err(zip::result::ZipError::FileNotFound) => Ok(None),
+        Err(err) => Err(err.to_string()),
+    }
+}
+
+/// Extract readable document text from a docx/pptx archive, skipping markup.
+#[wasm_bindgen]
+pub fn extract_text(bytes: &[u8]) -> Result<JsValue, JsValue> {
+    match extract_archive_text(bytes) {
+        Ok(summary) => serde_wasm_bindgen::to_value(&summary).map_err(|err| err.into()),
+        Err(err) => Err(JsValue::from_str(&err)),
+    }
+}
+
+fn extract_archive_text(bytes: &[u8]) -> Result<TextSummary, String> {
+    let cursor = Cursor::new(bytes.to_vec());
+    let mut archive = ZipArchive::new(cursor).map_err(|err| err.to_string())?;
+
+    let mut parts = Vec::new();
+
+    if let Ok(mut file) = archive.by_name("word/document.xml") {
+        let mut xml = String::new();
+        file.read_to_string(&mut xml).map_err(|err| err.to_string())?;
+        parts.push(PartText {
+            path: "word/document.xml".to_string(),
+            slide: None,
+            text: xml_to_text(&xml, "w:t", "w:p")?,
+        });
+    }
+
+    let slide_names: Vec<String> = archive
+        .file_names()
+        .filter(|name| {
+            name.starts_with("ppt/slides/slide") && name.ends_with(".xml")
+        })
+        .map(|name| name.to_string())
+        .collect();
+
+    let mut slide_names = slide_names;
+    slide_names.sort_by_key(|name| slide_index(name));
+
+    for (index, name) in slide_names.into_iter().enumerate() {
+        let mut file = archive.by_name(&name).map_err(|err| err.to_string())?;
+        let mut xml = String::new();
+        file.read_to_string(&mut xml).map_err(|err| err.to_string())?;
+        parts.push(PartText {
+            path: name,
+            slide: Some(index),
+            text: xml_to_text(&xml, "a:t", "a:p")?,
+        });
+    }
+
+    Ok(TextSummary { parts })
+}
+
+/// Extract the numeric suffix from `ppt/slides/slideN.xml` so slides sort in document order
+/// instead of lexicographically (slide10.xml before slide2.xml).
+fn slide_index(name: &str) -> usize {
+    name.rsplit('/')
+        .next()
+        .unwrap_or(name)
+        .trim_start_matches("slide")
+        .trim_end_matches(".xml")
+        .parse()
+        .unwrap_or(0)
+}
+
+/// Pull-parse `xml`, collecting character data inside `text_tag` elements (e.g. `w:t`),
+/// inserting a newline whenever a `para_tag` element (e.g. `w:p`) closes or an empty `br`
+/// element appears, and a tab for each empty `tab` element.
+fn xml_to_text(xml: &str, text_tag: &str, para_tag: &str) -> Result<String, String> {
+    let text_local = local_name(text_tag);
+    let para_local = local_name(para_tag);
+
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(false);
+
+    let mut out = String::new();
+    let mut in_text_elem = false;
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf).map_err(|err| err.to_string())? {
+            Event::Start(ref e) if e.local_name().as_ref() == text_local => {
+                in_text_elem = true;
+            }
+            Event::Text(ref e) if in_text_elem => {
+                out.push_str(&e.unescape().map_err(|err| err.to_string())?);
+            }
+            Event::End(ref e) if e.local_name().as_ref() == text_local => {
+                in_text_elem = false;
+            }
+            Event::End(ref e) if e.local_name().as_ref() == para_local => {
+                out.push('\n');
+            }
+            Event::Empty(ref e) if e.local_name().as_ref() == b"br" => {
+                out.push('\n');
+            }
+            Event::Empty(ref e) if e.local_name().as_ref() == b"tab" => {
+                out.push('\t');
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+        buf.clear();
+    }
+
+    Ok(out)
+}
+
+/// Strip a `prefix:local` QName down to just `local`.
+fn local_name(qname: &str) -> &[u8] {
+    qname.rsplit(':').next().unwrap_or(qname).as_bytes()
+}
+
+fn inspect_archive(
+    bytes: &[u8],
+    pretty: bool,
+    max_inline_bytes: u64,
+) -> Result<ArchiveSummary, String> {
+    let cursor = Cursor::new(bytes.to_vec());
+    let mut archive = ZipArchive::new(cursor).map_err(|err| err.to_string())?;
+
+    let mut entries = Vec::with_capacity(archive.len());
+    let mut raw_buffers = Vec::with_capacity(archive.len());
 
     for index in 0..archive.len() {
         let mut file = archive
@@ -50,7 +323,12 @@ fn inspect_archive(bytes: &[u8]) -> Result<ArchiveSummary, String> {
                 is_dir: true,
                 size: 0,
                 content: None,
+                formatted: None,
+                content_type: None,
+                data_uri: None,
+                parse_error: None,
             });
+            raw_buffers.push(Vec::new());
             continue;
         }
 
@@ -66,15 +344,71 @@ fn inspect_archive(bytes: &[u8]) -> Result<ArchiveSummary, String> {
             None
         };
 
+        let formatted = match (&content, pretty) {
+            (Some(text), true) => pretty_print_xml(text).ok(),
+            _ => None,
+        };
+
+        let parse_error = content.as_deref().and_then(validate_xml);
+
         entries.push(ArchiveEntry {
             path: name,
             is_dir: false,
             size,
             content,
+            formatted,
+            content_type: None,
+            data_uri: None,
+            parse_error,
         });
+        raw_buffers.push(buffer);
     }
 
-    Ok(ArchiveSummary { entries })
+    let content_types = entries
+        .iter()
+        .find(|entry| entry.path == "[Content_Types].xml" && entry.parse_error.is_none())
+        .and_then(|entry| entry.content.as_deref())
+        .map(ContentTypes::parse)
+        .transpose()?;
+
+    let mut relationships = Vec::new();
+    for entry in &entries {
+        if !entry.path.contains("/_rels/") && !entry.path.starts_with("_rels/") {
+            continue;
+        }
+        if let (Some(xml), None) = (&entry.content, &entry.parse_error) {
+            relationships.extend(relationships::parse_relationships(&entry.path, xml)?);
+        }
+    }
+
+    if let Some(content_types) = &content_types {
+        for entry in &mut entries {
+            if !entry.is_dir {
+                entry.content_type = content_types.resolve(&entry.path);
+            }
+        }
+    }
+
+    for (entry, buffer) in entries.iter_mut().zip(raw_buffers.iter()) {
+        if entry.is_dir
+            || entry.content.is_some()
+            || max_inline_bytes == 0
+            || entry.size > max_inline_bytes
+        {
+            continue;
+        }
+        let mime = entry
+            .content_type
+            .clone()
+            .or_else(|| mime_guess::from_path(&entry.path).first_raw().map(str::to_string))
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+        entry.data_uri = Some(format!("data:{mime};base64,{}", STANDARD.encode(buffer)));
+    }
+
+    Ok(ArchiveSummary {
+        entries,
+        relationships,
+    })
 }
 
 fn is_textual_entry(path: &str) -> bool {
@@ -84,6 +418,74 @@ fn is_textual_entry(path: &str) -> bool {
     )
 }
 
+/// Re-serialize `xml` through a reader/writer pass, normalizing indentation while preserving
+/// attribute order and CDATA sections.
+fn pretty_print_xml(xml: &str) -> Result<String, String> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(true);
+
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 2);
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf).map_err(|err| err.to_string())? {
+            Event::Eof => break,
+            event => writer
+                .write_event(event)
+                .map_err(|err| err.to_string())?,
+        }
+        buf.clear();
+    }
+
+    String::from_utf8(writer.into_inner()).map_err(|err| err.to_string())
+}
+
+/// Pull-parse `xml` purely to check well-formedness, returning the first error encountered
+/// (if any) with its line/column and byte offset.
+fn validate_xml(xml: &str) -> Option<XmlError> {
+    let mut reader = Reader::from_str(xml);
+    reader.trim_text(false);
+    let mut buf = Vec::new();
+
+    loop {
+        match reader.read_event_into(&mut buf) {
+            Ok(Event::Eof) => return None,
+            Ok(_) => {}
+            Err(err) => {
+                let byte_offset = reader.buffer_position();
+                let (line, column) = line_col(xml, byte_offset);
+                return Some(XmlError {
+                    message: err.to_string(),
+                    line,
+                    column,
+                    byte_offset,
+                });
+            }
+        }
+        buf.clear();
+    }
+}
+
+/// 1-indexed line/column of `byte_offset` within `text`.
+fn line_col(text: &str, byte_offset: usize) -> (usize, usize) {
+    let mut line = 1;
+    let mut column = 1;
+
+    for (idx, ch) in text.char_indices() {
+        if idx >= byte_offset {
+            break;
+        }
+        if ch == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    (line, column)
+}
+
 #[cfg(feature = "console_error_panic_hook")]
 fn set_panic_hook() {
     console_error_panic_hook::set_once();
@@ -116,7 +518,7 @@ mod tests {
             writer.finish().unwrap();
         }
 
-        let summary = inspect_archive(&buffer).expect("should parse zip");
+        let summary = inspect_archive(&buffer, false, 0).expect("should parse zip");
 
         assert_eq!(summary.entries.len(), 2);
         let doc_entry = summary
@@ -130,4 +532,247 @@ mod tests {
             Some("<w:document><w:t>Test</w:t></w:document>")
         );
     }
+
+    #[test]
+    fn extracts_docx_paragraph_text() {
+        let mut buffer = Vec::new();
+        {
+            let cursor = Cursor::new(&mut buffer);
+            let mut writer = zip::ZipWriter::new(cursor);
+            let options = FileOptions::default();
+
+            writer.start_file("word/document.xml", options).unwrap();
+            writer
+                .write_all(
+                    b"<w:document><w:body><w:p><w:r><w:t>Hello</w:t></w:r>\
+                      <w:r><w:tab/><w:t>World</w:t></w:r></w:p></w:body></w:document>",
+                )
+                .unwrap();
+            writer.finish().unwrap();
+        }
+
+        let summary = extract_archive_text(&buffer).expect("should extract text");
+
+        assert_eq!(summary.parts.len(), 1);
+        assert_eq!(summary.parts[0].path, "word/document.xml");
+        assert_eq!(summary.parts[0].slide, None);
+        assert_eq!(summary.parts[0].text, "Hello\tWorld\n");
+    }
+
+    #[test]
+    fn extracts_pptx_slide_text_in_order() {
+        let mut buffer = Vec::new();
+        {
+            let cursor = Cursor::new(&mut buffer);
+            let mut writer = zip::ZipWriter::new(cursor);
+            let options = FileOptions::default();
+
+            writer.start_file("ppt/slides/slide2.xml", options).unwrap();
+            writer
+                .write_all(b"<p:sld><p:txBody><a:p><a:r><a:t>Second</a:t></a:r></a:p></p:txBody></p:sld>")
+                .unwrap();
+            writer.start_file("ppt/slides/slide1.xml", options).unwrap();
+            writer
+                .write_all(b"<p:sld><p:txBody><a:p><a:r><a:t>First</a:t></a:r></a:p></p:txBody></p:sld>")
+                .unwrap();
+            writer.finish().unwrap();
+        }
+
+        let summary = extract_archive_text(&buffer).expect("should extract text");
+
+        assert_eq!(summary.parts.len(), 2);
+        assert_eq!(summary.parts[0].slide, Some(0));
+        assert_eq!(summary.parts[0].text, "First\n");
+        assert_eq!(summary.parts[1].slide, Some(1));
+        assert_eq!(summary.parts[1].text, "Second\n");
+    }
+
+    #[test]
+    fn pretty_printing_indents_nested_elements() {
+        let mut buffer = Vec::new();
+        {
+            let cursor = Cursor::new(&mut buffer);
+            let mut writer = zip::ZipWriter::new(cursor);
+            let options = FileOptions::default();
+
+            writer.start_file("word/document.xml", options).unwrap();
+            writer
+                .write_all(b"<w:document><w:body><w:p><w:t>Hi</w:t></w:p></w:body></w:document>")
+                .unwrap();
+            writer.finish().unwrap();
+        }
+
+        let summary = inspect_archive(&buffer, true, 0).expect("should parse zip");
+        let doc_entry = summary
+            .entries
+            .iter()
+            .find(|entry| entry.path == "word/document.xml")
+            .expect("document entry exists");
+
+        assert!(doc_entry.content.is_some());
+        let formatted = doc_entry.formatted.as_deref().expect("formatted content");
+        assert!(formatted.contains("\n  <w:body>"));
+        assert!(formatted.contains("\n    <w:p>"));
+    }
+
+    #[test]
+    fn builds_relationship_graph_and_resolves_content_types() {
+        let mut buffer = Vec::new();
+        {
+            let cursor = Cursor::new(&mut buffer);
+            let mut writer = zip::ZipWriter::new(cursor);
+            let options = FileOptions::default();
+
+            writer.start_file("[Content_Types].xml", options).unwrap();
+            writer
+                .write_all(
+                    br#"<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+                        <Default Extension="xml" ContentType="application/xml"/>
+                        <Override PartName="/word/document.xml" ContentType="application/word-document+xml"/>
+                    </Types>"#,
+                )
+                .unwrap();
+
+            writer
+                .start_file("word/_rels/document.xml.rels", options)
+                .unwrap();
+            writer
+                .write_all(
+                    br#"<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+                        <Relationship Id="rId1" Type="http://.../styles" Target="styles.xml"/>
+                    </Relationships>"#,
+                )
+                .unwrap();
+
+            writer.start_file("word/document.xml", options).unwrap();
+            writer.write_all(b"<w:document/>").unwrap();
+            writer.finish().unwrap();
+        }
+
+        let summary = inspect_archive(&buffer, false, 0).expect("should parse zip");
+
+        assert_eq!(summary.relationships.len(), 1);
+        assert_eq!(summary.relationships[0].source_part, "word/document.xml");
+        assert_eq!(summary.relationships[0].target, "word/styles.xml");
+
+        let doc_entry = summary
+            .entries
+            .iter()
+            .find(|entry| entry.path == "word/document.xml")
+            .expect("document entry exists");
+        assert_eq!(
+            doc_entry.content_type.as_deref(),
+            Some("application/word-document+xml")
+        );
+    }
+
+    #[test]
+    fn lists_entries_without_reading_payloads_then_reads_one_on_demand() {
+        let mut buffer = Vec::new();
+        {
+            let cursor = Cursor::new(&mut buffer);
+            let mut writer = zip::ZipWriter::new(cursor);
+            let options = FileOptions::default();
+
+            writer.add_directory("word/", options).unwrap();
+            writer.start_file("word/document.xml", options).unwrap();
+            writer.write_all(b"<w:document/>").unwrap();
+            writer.finish().unwrap();
+        }
+
+        let list = list_archive_entries(&buffer).expect("should list entries");
+        assert_eq!(list.entries.len(), 2);
+        let doc_meta = list
+            .entries
+            .iter()
+            .find(|entry| entry.path == "word/document.xml")
+            .expect("document entry exists");
+        assert_eq!(doc_meta.size, 13);
+        assert!(!doc_meta.is_dir);
+
+        let entry = read_archive_entry(&buffer, "word/document.xml").expect("should read entry");
+        assert_eq!(entry.content.as_deref(), Some("<w:document/>"));
+    }
+
+    #[test]
+    fn inlines_binary_parts_under_the_size_cap_as_data_uris() {
+        let mut buffer = Vec::new();
+        {
+            let cursor = Cursor::new(&mut buffer);
+            let mut writer = zip::ZipWriter::new(cursor);
+            let options = FileOptions::default();
+
+            writer.start_file("ppt/media/image1.png", options).unwrap();
+            writer.write_all(&[0x89, b'P', b'N', b'G', 0x0d, 0x0a]).unwrap();
+            writer.finish().unwrap();
+        }
+
+        let summary = inspect_archive(&buffer, false, 6).expect("should parse zip");
+        let image = summary
+            .entries
+            .iter()
+            .find(|entry| entry.path == "ppt/media/image1.png")
+            .expect("image entry exists");
+        assert_eq!(
+            image.data_uri.as_deref(),
+            Some("data:image/png;base64,iVBORw0K")
+        );
+
+        let summary = inspect_archive(&buffer, false, 5).expect("should parse zip");
+        let image = summary
+            .entries
+            .iter()
+            .find(|entry| entry.path == "ppt/media/image1.png")
+            .expect("image entry exists");
+        assert_eq!(image.data_uri, None);
+    }
+
+    #[test]
+    fn reports_parse_error_position_for_malformed_xml() {
+        let mut buffer = Vec::new();
+        {
+            let cursor = Cursor::new(&mut buffer);
+            let mut writer = zip::ZipWriter::new(cursor);
+            let options = FileOptions::default();
+
+            writer.start_file("word/document.xml", options).unwrap();
+            writer
+                .write_all(b"<w:document>\n<w:p></w:document>")
+                .unwrap();
+            writer.finish().unwrap();
+        }
+
+        let summary = inspect_archive(&buffer, false, 0).expect("should parse zip");
+        let doc_entry = summary
+            .entries
+            .iter()
+            .find(|entry| entry.path == "word/document.xml")
+            .expect("document entry exists");
+
+        let parse_error = doc_entry.parse_error.as_ref().expect("expected a parse error");
+        assert_eq!(parse_error.line, 2);
+    }
+
+    #[test]
+    fn well_formed_xml_has_no_parse_error() {
+        let mut buffer = Vec::new();
+        {
+            let cursor = Cursor::new(&mut buffer);
+            let mut writer = zip::ZipWriter::new(cursor);
+            let options = FileOptions::default();
+
+            writer.start_file("word/document.xml", options).unwrap();
+            writer.write_all(b"<w:document><w:t>Hi</w:t></w:document>").unwrap();
+            writer.finish().unwrap();
+        }
+
+        let summary = inspect_archive(&buffer, false, 0).expect("should parse zip");
+        let doc_entry = summary
+            .entries
+            .iter()
+            .find(|entry| entry.path == "word/document.xml")
+            .expect("document entry exists");
+
+        assert!(doc_entry.parse_error.is_none());
+    }
 }
@@ -0,0 +1,211 @@
+//! OPC relationship graph: parses `*/_rels/*.rels` parts and `[Content_Types].xml` so callers
+//! can see how parts link to each other instead of just a flat file list.
+
+use std::collections::HashMap;
+
+use quick_xml::de::from_str;
+use serde::{Deserialize, Serialize};
+
+/// A single `<Relationship>` entry, with `target` resolved to an archive-relative path
+/// (targets containing `://` are kept unchanged).
+#[derive(Serialize, Clone)]
+pub struct Relationship {
+    pub source_part: String,
+    pub id: String,
+    #[serde(rename = "type")]
+    pub rel_type: String,
+    pub target: String,
+    pub target_mode: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct RelationshipsXml {
+    #[serde(rename = "Relationship", default)]
+    relationship: Vec<RelationshipXml>,
+}
+
+#[derive(Deserialize)]
+struct RelationshipXml {
+    #[serde(rename = "@Id")]
+    id: String,
+    #[serde(rename = "@Type")]
+    r#type: String,
+    #[serde(rename = "@Target")]
+    target: String,
+    #[serde(rename = "@TargetMode", default)]
+    target_mode: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct ContentTypesXml {
+    #[serde(rename = "Default", default)]
+    default: Vec<DefaultXml>,
+    #[serde(rename = "Override", default)]
+    r#override: Vec<OverrideXml>,
+}
+
+#[derive(Deserialize)]
+struct DefaultXml {
+    #[serde(rename = "@Extension")]
+    extension: String,
+    #[serde(rename = "@ContentType")]
+    content_type: String,
+}
+
+#[derive(Deserialize)]
+struct OverrideXml {
+    #[serde(rename = "@PartName")]
+    part_name: String,
+    #[serde(rename = "@ContentType")]
+    content_type: String,
+}
+
+/// Resolves a part's content type, following the OPC precedence of part-specific `Override`
+/// entries over extension-based `Default` entries.
+pub struct ContentTypes {
+    defaults: HashMap<String, String>,
+    overrides: HashMap<String, String>,
+}
+
+impl ContentTypes {
+    pub fn parse(xml: &str) -> Result<Self, String> {
+        let parsed: ContentTypesXml = from_str(xml).map_err(|err| err.to_string())?;
+
+        let defaults = parsed
+            .default
+            .into_iter()
+            .map(|entry| (entry.extension.to_ascii_lowercase(), entry.content_type))
+            .collect();
+        let overrides = parsed
+            .r#override
+            .into_iter()
+            .map(|entry| (entry.part_name, entry.content_type))
+            .collect();
+
+        Ok(ContentTypes { defaults, overrides })
+    }
+
+    /// Look up the content type for an archive path such as `word/document.xml`.
+    pub fn resolve(&self, path: &str) -> Option<String> {
+        let part_name = format!("/{path}");
+        if let Some(content_type) = self.overrides.get(&part_name) {
+            return Some(content_type.clone());
+        }
+
+        let extension = path.rsplit('.').next()?.to_ascii_lowercase();
+        self.defaults.get(&extension).cloned()
+    }
+}
+
+/// Parse a `.rels` part's relationships, resolving each `Target` relative to the directory of
+/// the part that owns it (e.g. `word/document.xml` owns `word/_rels/document.xml.rels`).
+pub fn parse_relationships(rels_path: &str, xml: &str) -> Result<Vec<Relationship>, String> {
+    let source_part = owning_part(rels_path);
+    let base_dir = parent_dir(&source_part);
+
+    let parsed: RelationshipsXml = from_str(xml).map_err(|err| err.to_string())?;
+
+    Ok(parsed
+        .relationship
+        .into_iter()
+        .map(|rel| Relationship {
+            source_part: source_part.clone(),
+            id: rel.id,
+            rel_type: rel.r#type,
+            target: resolve_target(base_dir, &rel.target),
+            target_mode: rel.target_mode,
+        })
+        .collect())
+}
+
+/// `word/_rels/document.xml.rels` -> `word/document.xml`.
+fn owning_part(rels_path: &str) -> String {
+    let without_extension = rels_path.trim_end_matches(".rels");
+    let file_name = without_extension.rsplit('/').next().unwrap_or(without_extension);
+
+    match rels_path.rsplit_once("/_rels/") {
+        Some((dir, _)) if !dir.is_empty() => format!("{dir}/{file_name}"),
+        _ => file_name.to_string(),
+    }
+}
+
+fn parent_dir(path: &str) -> &str {
+    path.rsplit_once('/').map(|(dir, _)| dir).unwrap_or("")
+}
+
+/// Resolve a relationship `target` against `base_dir`, handling `../` segments and
+/// archive-absolute (`/word/document.xml`) and external (`http://...`) targets.
+fn resolve_target(base_dir: &str, target: &str) -> String {
+    if let Some(absolute) = target.strip_prefix('/') {
+        return absolute.to_string();
+    }
+    if target.contains("://") {
+        return target.to_string();
+    }
+
+    let mut segments: Vec<&str> = if base_dir.is_empty() {
+        Vec::new()
+    } else {
+        base_dir.split('/').collect()
+    };
+
+    for part in target.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                segments.pop();
+            }
+            _ => segments.push(part),
+        }
+    }
+
+    segments.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_relationship_targets_relative_to_owning_part() {
+        let xml = r#"<?xml version="1.0"?>
+            <Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships">
+                <Relationship Id="rId1" Type="http://.../styles" Target="styles.xml"/>
+                <Relationship Id="rId2" Type="http://.../image" Target="../media/image1.png"/>
+            </Relationships>"#;
+
+        let relationships =
+            parse_relationships("word/_rels/document.xml.rels", xml).expect("should parse");
+
+        assert_eq!(relationships.len(), 2);
+        assert_eq!(relationships[0].source_part, "word/document.xml");
+        assert_eq!(relationships[0].target, "word/styles.xml");
+        assert_eq!(relationships[1].target, "media/image1.png");
+    }
+
+    #[test]
+    fn resolves_content_types_with_override_precedence() {
+        let xml = r#"<?xml version="1.0"?>
+            <Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types">
+                <Default Extension="xml" ContentType="application/xml"/>
+                <Default Extension="png" ContentType="image/png"/>
+                <Override PartName="/word/document.xml"
+                          ContentType="application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml"/>
+            </Types>"#;
+
+        let content_types = ContentTypes::parse(xml).expect("should parse");
+
+        assert_eq!(
+            content_types.resolve("word/document.xml").as_deref(),
+            Some("application/vnd.openxmlformats-officedocument.wordprocessingml.document.main+xml")
+        );
+        assert_eq!(
+            content_types.resolve("word/styles.xml").as_deref(),
+            Some("application/xml")
+        );
+        assert_eq!(
+            content_types.resolve("ppt/media/image1.png").as_deref(),
+            Some("image/png")
+        );
+    }
+}